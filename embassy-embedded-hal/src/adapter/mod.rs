@@ -0,0 +1,3 @@
+mod yielding_async;
+
+pub use yielding_async::YieldingAsync;
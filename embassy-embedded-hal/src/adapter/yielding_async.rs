@@ -6,12 +6,45 @@ use embassy_futures::yield_now;
 /// between long running blocking operations.
 pub struct YieldingAsync<T> {
     wrapped: T,
+    budget: usize,
+    counter: usize,
 }
 
 impl<T> YieldingAsync<T> {
     /// Create a new instance of a wrapper that yields after each operation.
     pub fn new(wrapped: T) -> Self {
-        Self { wrapped }
+        Self::with_budget(wrapped, 1)
+    }
+
+    /// Create a new instance of a wrapper that only yields once every `n` operations,
+    /// counted across all trait methods.
+    ///
+    /// This trades fairness for throughput: issuing thousands of tiny transfers no longer
+    /// re-enters the executor after each one. `n == 1` is equivalent to [`new`](Self::new).
+    pub fn with_budget(wrapped: T, n: usize) -> Self {
+        Self {
+            wrapped,
+            budget: n,
+            counter: n,
+        }
+    }
+
+    /// Yield to the executor once the configured budget of operations has been spent,
+    /// then reset the counter.
+    ///
+    /// The counter is reset before the `yield_now().await` point, not after, so a future
+    /// dropped while suspended there (a cancelled `select!` branch, a timeout) still leaves
+    /// it at a valid, positive value instead of zero.
+    async fn maybe_yield(&mut self) {
+        if self.budget == 0 {
+            return;
+        }
+        if self.counter <= 1 {
+            self.counter = self.budget;
+            yield_now().await;
+        } else {
+            self.counter -= 1;
+        }
     }
 }
 
@@ -31,19 +64,19 @@ where
 {
     async fn read(&mut self, address: u8, read: &mut [u8]) -> Result<(), Self::Error> {
         self.wrapped.read(address, read).await?;
-        yield_now().await;
+        self.maybe_yield().await;
         Ok(())
     }
 
     async fn write(&mut self, address: u8, write: &[u8]) -> Result<(), Self::Error> {
         self.wrapped.write(address, write).await?;
-        yield_now().await;
+        self.maybe_yield().await;
         Ok(())
     }
 
     async fn write_read(&mut self, address: u8, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
         self.wrapped.write_read(address, write, read).await?;
-        yield_now().await;
+        self.maybe_yield().await;
         Ok(())
     }
 
@@ -53,7 +86,7 @@ where
         operations: &mut [embedded_hal_1::i2c::Operation<'_>],
     ) -> Result<(), Self::Error> {
         self.wrapped.transaction(address, operations).await?;
-        yield_now().await;
+        self.maybe_yield().await;
         Ok(())
     }
 }
@@ -71,50 +104,35 @@ where
 
 impl<T> embedded_hal_async::spi::SpiBus<u8> for YieldingAsync<T>
 where
-    T: embedded_hal_async::spi::SpiBus,
+    T: embedded_hal_async::spi::SpiBus<u8>,
 {
-    async fn transfer<'a>(&'a mut self, read: &'a mut [u8], write: &'a [u8]) -> Result<(), Self::Error> {
-        self.wrapped.transfer(read, write).await?;
-        yield_now().await;
+    async fn read(&mut self, data: &mut [u8]) -> Result<(), Self::Error> {
+        self.wrapped.read(data).await?;
+        self.maybe_yield().await;
         Ok(())
     }
 
-    async fn transfer_in_place<'a>(&'a mut self, words: &'a mut [u8]) -> Result<(), Self::Error> {
-        self.wrapped.transfer_in_place(words).await?;
-        yield_now().await;
+    async fn write(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.wrapped.write(data).await?;
+        self.maybe_yield().await;
         Ok(())
     }
-}
 
-impl<T> embedded_hal_async::spi::SpiBusFlush for YieldingAsync<T>
-where
-    T: embedded_hal_async::spi::SpiBusFlush,
-{
-    async fn flush(&mut self) -> Result<(), Self::Error> {
-        self.wrapped.flush().await?;
-        yield_now().await;
+    async fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+        self.wrapped.transfer(read, write).await?;
+        self.maybe_yield().await;
         Ok(())
     }
-}
 
-impl<T> embedded_hal_async::spi::SpiBusWrite<u8> for YieldingAsync<T>
-where
-    T: embedded_hal_async::spi::SpiBusWrite<u8>,
-{
-    async fn write(&mut self, data: &[u8]) -> Result<(), Self::Error> {
-        self.wrapped.write(data).await?;
-        yield_now().await;
+    async fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        self.wrapped.transfer_in_place(words).await?;
+        self.maybe_yield().await;
         Ok(())
     }
-}
 
-impl<T> embedded_hal_async::spi::SpiBusRead<u8> for YieldingAsync<T>
-where
-    T: embedded_hal_async::spi::SpiBusRead<u8>,
-{
-    async fn read(&mut self, data: &mut [u8]) -> Result<(), Self::Error> {
-        self.wrapped.read(data).await?;
-        yield_now().await;
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.wrapped.flush().await?;
+        self.maybe_yield().await;
         Ok(())
     }
 }
@@ -132,7 +150,14 @@ impl<T: embedded_storage_async::nor_flash::ReadNorFlash> embedded_storage_async:
     const READ_SIZE: usize = T::READ_SIZE;
 
     async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
-        self.wrapped.read(offset, bytes).await?;
+        // Yield between each chunk of the read
+        let mut pos = 0;
+        while pos < bytes.len() {
+            let end = core::cmp::min(pos + Self::READ_SIZE, bytes.len());
+            self.wrapped.read(offset + pos as u32, &mut bytes[pos..end]).await?;
+            self.maybe_yield().await;
+            pos = end;
+        }
         Ok(())
     }
 
@@ -146,8 +171,14 @@ impl<T: embedded_storage_async::nor_flash::NorFlash> embedded_storage_async::nor
     const ERASE_SIZE: usize = T::ERASE_SIZE;
 
     async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
-        self.wrapped.write(offset, bytes).await?;
-        yield_now().await;
+        // Yield between each chunk of the write
+        let mut pos = 0;
+        while pos < bytes.len() {
+            let end = core::cmp::min(pos + Self::WRITE_SIZE, bytes.len());
+            self.wrapped.write(offset + pos as u32, &bytes[pos..end]).await?;
+            self.maybe_yield().await;
+            pos = end;
+        }
         Ok(())
     }
 
@@ -156,32 +187,206 @@ impl<T: embedded_storage_async::nor_flash::NorFlash> embedded_storage_async::nor
         for from in (from..to).step_by(T::ERASE_SIZE) {
             let to = core::cmp::min(from + T::ERASE_SIZE as u32, to);
             self.wrapped.erase(from, to).await?;
-            yield_now().await;
+            self.maybe_yield().await;
         }
         Ok(())
     }
 }
 
+//
+// embedded-io-async implementations
+//
+impl<T: embedded_io::ErrorType> embedded_io::ErrorType for YieldingAsync<T> {
+    type Error = T::Error;
+}
+
+impl<T: embedded_io_async::Read> embedded_io_async::Read for YieldingAsync<T> {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let n = self.wrapped.read(buf).await?;
+        self.maybe_yield().await;
+        Ok(n)
+    }
+}
+
+impl<T: embedded_io_async::Write> embedded_io_async::Write for YieldingAsync<T> {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let n = self.wrapped.write(buf).await?;
+        self.maybe_yield().await;
+        Ok(n)
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.wrapped.flush().await?;
+        self.maybe_yield().await;
+        Ok(())
+    }
+}
+
+impl<T: embedded_io_async::BufRead> embedded_io_async::BufRead for YieldingAsync<T> {
+    async fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        self.maybe_yield().await;
+        self.wrapped.fill_buf().await
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.wrapped.consume(amt)
+    }
+}
+
+impl<T: embedded_io_async::Seek> embedded_io_async::Seek for YieldingAsync<T> {
+    async fn seek(&mut self, pos: embedded_io::SeekFrom) -> Result<u64, Self::Error> {
+        let pos = self.wrapped.seek(pos).await?;
+        self.maybe_yield().await;
+        Ok(pos)
+    }
+}
+
+//
+// nb-based passthrough (embedded-hal-nb serial, embedded-hal 0.2 SPI `FullDuplex`)
+//
+// These traits are nb-based rather than async, so there's no `yield_now` equivalent to
+// call while we're `WouldBlock`-ing: we just forward the wrapped driver's nb result as-is.
+//
+#[cfg(feature = "embedded-hal-02")]
+impl<T> embedded_hal_nb::serial::ErrorType for YieldingAsync<T>
+where
+    T: embedded_hal_nb::serial::ErrorType,
+{
+    type Error = T::Error;
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl<T> embedded_hal_nb::serial::Read<u8> for YieldingAsync<T>
+where
+    T: embedded_hal_nb::serial::Read<u8>,
+{
+    fn read(&mut self) -> nb::Result<u8, Self::Error> {
+        self.wrapped.read()
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl<T> embedded_hal_nb::serial::Write<u8> for YieldingAsync<T>
+where
+    T: embedded_hal_nb::serial::Write<u8>,
+{
+    fn write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        self.wrapped.write(word)
+    }
+
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.wrapped.flush()
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl<T> embedded_hal_02::spi::FullDuplex<u8> for YieldingAsync<T>
+where
+    T: embedded_hal_02::spi::FullDuplex<u8>,
+{
+    type Error = T::Error;
+
+    fn read(&mut self) -> nb::Result<u8, Self::Error> {
+        self.wrapped.read()
+    }
+
+    fn send(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        self.wrapped.send(word)
+    }
+}
+
+//
+// embedded-hal 0.2 blocking passthrough
+//
+// Unlike the nb traits above these calls run to completion, but since they're synchronous
+// there's still nowhere to `.await` a yield; a legacy blocking driver wrapped here gets no
+// fairness guarantee, only the ability to sit alongside async consumers of the same peripheral.
+//
+#[cfg(feature = "embedded-hal-02")]
+impl<T> embedded_hal_02::blocking::i2c::Write for YieldingAsync<T>
+where
+    T: embedded_hal_02::blocking::i2c::Write,
+{
+    type Error = T::Error;
+
+    fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.wrapped.write(addr, bytes)
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl<T> embedded_hal_02::blocking::i2c::Read for YieldingAsync<T>
+where
+    T: embedded_hal_02::blocking::i2c::Read,
+{
+    type Error = T::Error;
+
+    fn read(&mut self, addr: u8, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.wrapped.read(addr, bytes)
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl<T> embedded_hal_02::blocking::i2c::WriteRead for YieldingAsync<T>
+where
+    T: embedded_hal_02::blocking::i2c::WriteRead,
+{
+    type Error = T::Error;
+
+    fn write_read(&mut self, addr: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.wrapped.write_read(addr, bytes, buffer)
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl<T> embedded_hal_02::blocking::spi::Transfer<u8> for YieldingAsync<T>
+where
+    T: embedded_hal_02::blocking::spi::Transfer<u8>,
+{
+    type Error = T::Error;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        self.wrapped.transfer(words)
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl<T> embedded_hal_02::blocking::spi::Write<u8> for YieldingAsync<T>
+where
+    T: embedded_hal_02::blocking::spi::Write<u8>,
+{
+    type Error = T::Error;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.wrapped.write(words)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use embedded_storage_async::nor_flash::NorFlash;
+    use embedded_storage_async::nor_flash::{NorFlash, ReadNorFlash};
 
     use super::*;
 
     extern crate std;
 
     #[derive(Default)]
-    struct FakeFlash(Vec<(u32, u32)>);
+    struct FakeFlash {
+        erases: Vec<(u32, u32)>,
+        reads: Vec<(u32, u32)>,
+        writes: Vec<(u32, u32)>,
+    }
 
     impl embedded_storage::nor_flash::ErrorType for FakeFlash {
         type Error = std::convert::Infallible;
     }
 
     impl embedded_storage_async::nor_flash::ReadNorFlash for FakeFlash {
-        const READ_SIZE: usize = 1;
+        const READ_SIZE: usize = 16;
 
-        async fn read(&mut self, _offset: u32, _bytes: &mut [u8]) -> Result<(), Self::Error> {
-            unimplemented!()
+        async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            self.reads.push((offset, offset + bytes.len() as u32));
+            Ok(())
         }
 
         fn capacity(&self) -> usize {
@@ -193,12 +398,13 @@ mod tests {
         const WRITE_SIZE: usize = 4;
         const ERASE_SIZE: usize = 128;
 
-        async fn write(&mut self, _offset: u32, _bytes: &[u8]) -> Result<(), Self::Error> {
-            unimplemented!()
+        async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+            self.writes.push((offset, offset + bytes.len() as u32));
+            Ok(())
         }
 
         async fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
-            self.0.push((from, to));
+            self.erases.push((from, to));
             Ok(())
         }
     }
@@ -211,9 +417,9 @@ mod tests {
         yielding.erase(0, 256).await.unwrap();
 
         let fake = yielding.wrapped;
-        assert_eq!(2, fake.0.len());
-        assert_eq!((0, 128), fake.0[0]);
-        assert_eq!((128, 256), fake.0[1]);
+        assert_eq!(2, fake.erases.len());
+        assert_eq!((0, 128), fake.erases[0]);
+        assert_eq!((128, 256), fake.erases[1]);
     }
 
     #[futures_test::test]
@@ -224,9 +430,371 @@ mod tests {
         yielding.erase(0, 257).await.unwrap();
 
         let fake = yielding.wrapped;
-        assert_eq!(3, fake.0.len());
-        assert_eq!((0, 128), fake.0[0]);
-        assert_eq!((128, 256), fake.0[1]);
-        assert_eq!((256, 257), fake.0[2]);
+        assert_eq!(3, fake.erases.len());
+        assert_eq!((0, 128), fake.erases[0]);
+        assert_eq!((128, 256), fake.erases[1]);
+        assert_eq!((256, 257), fake.erases[2]);
+    }
+
+    #[futures_test::test]
+    async fn can_read() {
+        let fake = FakeFlash::default();
+        let mut yielding = YieldingAsync::new(fake);
+        let mut buf = [0u8; 32];
+
+        yielding.read(0, &mut buf).await.unwrap();
+
+        let fake = yielding.wrapped;
+        assert_eq!(2, fake.reads.len());
+        assert_eq!((0, 16), fake.reads[0]);
+        assert_eq!((16, 32), fake.reads[1]);
+    }
+
+    #[futures_test::test]
+    async fn can_read_trailing_partial_chunk() {
+        let fake = FakeFlash::default();
+        let mut yielding = YieldingAsync::new(fake);
+        let mut buf = [0u8; 33];
+
+        yielding.read(100, &mut buf).await.unwrap();
+
+        let fake = yielding.wrapped;
+        assert_eq!(3, fake.reads.len());
+        assert_eq!((100, 116), fake.reads[0]);
+        assert_eq!((116, 132), fake.reads[1]);
+        assert_eq!((132, 133), fake.reads[2]);
+    }
+
+    #[futures_test::test]
+    async fn can_write_chunked() {
+        let fake = FakeFlash::default();
+        let mut yielding = YieldingAsync::new(fake);
+        let buf = [0u8; 9];
+
+        yielding.write(0, &buf).await.unwrap();
+
+        let fake = yielding.wrapped;
+        assert_eq!(3, fake.writes.len());
+        assert_eq!((0, 4), fake.writes[0]);
+        assert_eq!((4, 8), fake.writes[1]);
+        assert_eq!((8, 9), fake.writes[2]);
+    }
+
+    #[futures_test::test]
+    async fn with_budget_does_not_change_call_pattern() {
+        let fake = FakeFlash::default();
+        let mut yielding = YieldingAsync::with_budget(fake, 4);
+
+        yielding.erase(0, 257).await.unwrap();
+
+        let fake = yielding.wrapped;
+        assert_eq!(3, fake.erases.len());
+        assert_eq!((0, 128), fake.erases[0]);
+        assert_eq!((128, 256), fake.erases[1]);
+        assert_eq!((256, 257), fake.erases[2]);
+    }
+
+    use embedded_io_async::{BufRead as _, Read as _, Seek as _, Write as _};
+
+    #[derive(Default)]
+    struct FakeIo {
+        read_data: Vec<u8>,
+        read_pos: usize,
+        written: Vec<u8>,
+        flushes: usize,
+        fill_buf_calls: usize,
+        seeks: Vec<embedded_io::SeekFrom>,
+    }
+
+    impl embedded_io::ErrorType for FakeIo {
+        type Error = std::convert::Infallible;
+    }
+
+    impl embedded_io_async::Read for FakeIo {
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let n = core::cmp::min(buf.len(), self.read_data.len() - self.read_pos);
+            buf[..n].copy_from_slice(&self.read_data[self.read_pos..self.read_pos + n]);
+            self.read_pos += n;
+            Ok(n)
+        }
+    }
+
+    impl embedded_io_async::Write for FakeIo {
+        async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+            self.written.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        async fn flush(&mut self) -> Result<(), Self::Error> {
+            self.flushes += 1;
+            Ok(())
+        }
+    }
+
+    impl embedded_io_async::BufRead for FakeIo {
+        async fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+            self.fill_buf_calls += 1;
+            Ok(&self.read_data[self.read_pos..])
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.read_pos += amt;
+        }
+    }
+
+    impl embedded_io_async::Seek for FakeIo {
+        async fn seek(&mut self, pos: embedded_io::SeekFrom) -> Result<u64, Self::Error> {
+            self.seeks.push(pos);
+            self.read_pos = 7;
+            Ok(7)
+        }
+    }
+
+    #[futures_test::test]
+    async fn io_read_forwards_to_wrapped() {
+        let fake = FakeIo {
+            read_data: std::vec![1, 2, 3],
+            ..Default::default()
+        };
+        let mut yielding = YieldingAsync::new(fake);
+
+        let mut buf = [0u8; 3];
+        let n = yielding.read(&mut buf).await.unwrap();
+
+        assert_eq!(3, n);
+        assert_eq!([1, 2, 3], buf);
+    }
+
+    #[futures_test::test]
+    async fn io_write_and_flush_forward_to_wrapped() {
+        let mut yielding = YieldingAsync::new(FakeIo::default());
+
+        let n = yielding.write(&[4, 5, 6]).await.unwrap();
+        yielding.flush().await.unwrap();
+
+        assert_eq!(3, n);
+        let fake = yielding.wrapped;
+        assert_eq!(std::vec![4, 5, 6], fake.written);
+        assert_eq!(1, fake.flushes);
+    }
+
+    #[futures_test::test]
+    async fn io_buf_read_fill_and_consume_forward_to_wrapped() {
+        let fake = FakeIo {
+            read_data: std::vec![1, 2, 3, 4],
+            ..Default::default()
+        };
+        let mut yielding = YieldingAsync::new(fake);
+
+        let chunk = yielding.fill_buf().await.unwrap().to_vec();
+        assert_eq!(std::vec![1, 2, 3, 4], chunk);
+        yielding.consume(2);
+
+        let chunk = yielding.fill_buf().await.unwrap().to_vec();
+        assert_eq!(std::vec![3, 4], chunk);
+
+        let fake = yielding.wrapped;
+        assert_eq!(2, fake.fill_buf_calls);
+    }
+
+    #[futures_test::test]
+    async fn io_seek_forwards_to_wrapped() {
+        let mut yielding = YieldingAsync::new(FakeIo::default());
+
+        let pos = yielding.seek(embedded_io::SeekFrom::Start(7)).await.unwrap();
+
+        assert_eq!(7, pos);
+        let fake = yielding.wrapped;
+        assert_eq!(std::vec![embedded_io::SeekFrom::Start(7)], fake.seeks);
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    #[derive(Default)]
+    struct FakeNbSerial {
+        read_calls: usize,
+        written: Vec<u8>,
+        flush_calls: usize,
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    impl embedded_hal_nb::serial::ErrorType for FakeNbSerial {
+        type Error = std::convert::Infallible;
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    impl embedded_hal_nb::serial::Read<u8> for FakeNbSerial {
+        fn read(&mut self) -> nb::Result<u8, Self::Error> {
+            self.read_calls += 1;
+            Ok(42)
+        }
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    impl embedded_hal_nb::serial::Write<u8> for FakeNbSerial {
+        fn write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+            self.written.push(word);
+            Ok(())
+        }
+
+        fn flush(&mut self) -> nb::Result<(), Self::Error> {
+            self.flush_calls += 1;
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    #[test]
+    fn serial_nb_passthrough_forwards_to_wrapped() {
+        use embedded_hal_nb::serial::{Read, Write};
+
+        let mut yielding = YieldingAsync::new(FakeNbSerial::default());
+
+        let byte = yielding.read().unwrap();
+        yielding.write(9).unwrap();
+        yielding.flush().unwrap();
+
+        assert_eq!(42, byte);
+        let fake = yielding.wrapped;
+        assert_eq!(1, fake.read_calls);
+        assert_eq!(std::vec![9], fake.written);
+        assert_eq!(1, fake.flush_calls);
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    #[derive(Default)]
+    struct FakeFullDuplex {
+        sent: Vec<u8>,
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    impl embedded_hal_02::spi::FullDuplex<u8> for FakeFullDuplex {
+        type Error = std::convert::Infallible;
+
+        fn read(&mut self) -> nb::Result<u8, Self::Error> {
+            Ok(7)
+        }
+
+        fn send(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+            self.sent.push(word);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    #[test]
+    fn spi_full_duplex_nb_passthrough_forwards_to_wrapped() {
+        let mut yielding = YieldingAsync::new(FakeFullDuplex::default());
+
+        let byte = embedded_hal_02::spi::FullDuplex::read(&mut yielding).unwrap();
+        embedded_hal_02::spi::FullDuplex::send(&mut yielding, 3).unwrap();
+
+        assert_eq!(7, byte);
+        assert_eq!(std::vec![3], yielding.wrapped.sent);
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    #[derive(Default)]
+    struct FakeI2c02 {
+        writes: Vec<(u8, Vec<u8>)>,
+        reads: Vec<u8>,
+        write_reads: Vec<u8>,
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    impl embedded_hal_02::blocking::i2c::Write for FakeI2c02 {
+        type Error = std::convert::Infallible;
+
+        fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+            self.writes.push((addr, bytes.to_vec()));
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    impl embedded_hal_02::blocking::i2c::Read for FakeI2c02 {
+        type Error = std::convert::Infallible;
+
+        fn read(&mut self, addr: u8, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            self.reads.push(addr);
+            bytes.fill(addr);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    impl embedded_hal_02::blocking::i2c::WriteRead for FakeI2c02 {
+        type Error = std::convert::Infallible;
+
+        fn write_read(&mut self, addr: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+            self.write_reads.push(addr);
+            buffer.copy_from_slice(bytes);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    #[test]
+    fn i2c_02_blocking_passthrough_forwards_to_wrapped() {
+        use embedded_hal_02::blocking::i2c::{Read, Write, WriteRead};
+
+        let mut yielding = YieldingAsync::new(FakeI2c02::default());
+
+        yielding.write(0x50, &[1, 2]).unwrap();
+        let mut buf = [0u8; 2];
+        yielding.read(0x51, &mut buf).unwrap();
+        yielding.write_read(0x52, &[9, 9], &mut [0u8; 2]).unwrap();
+
+        assert_eq!([0x51, 0x51], buf);
+        let fake = yielding.wrapped;
+        assert_eq!(std::vec![(0x50, std::vec![1, 2])], fake.writes);
+        assert_eq!(std::vec![0x51], fake.reads);
+        assert_eq!(std::vec![0x52], fake.write_reads);
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    #[derive(Default)]
+    struct FakeSpi02 {
+        transferred: Vec<u8>,
+        written: Vec<u8>,
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    impl embedded_hal_02::blocking::spi::Transfer<u8> for FakeSpi02 {
+        type Error = std::convert::Infallible;
+
+        fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+            self.transferred.extend_from_slice(words);
+            for w in words.iter_mut() {
+                *w = !*w;
+            }
+            Ok(words)
+        }
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    impl embedded_hal_02::blocking::spi::Write<u8> for FakeSpi02 {
+        type Error = std::convert::Infallible;
+
+        fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+            self.written.extend_from_slice(words);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "embedded-hal-02")]
+    #[test]
+    fn spi_02_blocking_passthrough_forwards_to_wrapped() {
+        use embedded_hal_02::blocking::spi::{Transfer, Write};
+
+        let mut yielding = YieldingAsync::new(FakeSpi02::default());
+
+        let mut words = [0x0F, 0xF0];
+        let result = yielding.transfer(&mut words).unwrap().to_vec();
+        yielding.write(&[1, 2, 3]).unwrap();
+
+        assert_eq!(std::vec![0xF0, 0x0F], result);
+        let fake = yielding.wrapped;
+        assert_eq!(std::vec![0x0F, 0xF0], fake.transferred);
+        assert_eq!(std::vec![1, 2, 3], fake.written);
     }
 }
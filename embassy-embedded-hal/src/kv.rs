@@ -0,0 +1,670 @@
+//! Log-structured, wear-leveling key/value store over any [`NorFlash`].
+//!
+//! Records are appended sequentially to a flash region as
+//! `[key_len: u32][key][0x3D][value_len: u32][value]`, padded up to the next `WRITE_SIZE`
+//! boundary. A lookup scans the log from the start, keeping the *last* record seen for a
+//! key, since later writes shadow earlier ones; a record with `value_len == 0` marks a
+//! deletion. When the region can't fit a new record, [`FlashKv`] compacts: it replays the
+//! live key/value pairs into a caller-supplied scratch buffer, erases the region, and
+//! rewrites only the survivors to the front.
+//!
+//! This composes naturally with [`YieldingAsync`](crate::adapter::YieldingAsync): wrap the
+//! flash passed to [`FlashKv::new`] so that the reads and writes driven by compaction also
+//! yield to the executor.
+
+use embedded_storage_async::nor_flash::NorFlash;
+
+/// Separator byte written between a record's key and its value length.
+const SEPARATOR: u8 = 0x3D;
+
+/// Maximum key length supported by this store.
+///
+/// Keys are compared on the stack while scanning the log, so they're capped rather than
+/// heap-allocated.
+const MAX_KEY_LEN: usize = 64;
+
+/// Errors returned by [`FlashKv`].
+#[derive(Debug)]
+pub enum Error<E> {
+    /// The region (even after compaction) has no room for the new record, or the caller's
+    /// `scratch` buffer is too small to stage the record or the live records that compaction
+    /// rewrites.
+    SpaceExhausted,
+    /// A record's length field claims more bytes than are present in the written region.
+    Truncated {
+        /// Offset of the record whose length field runs past the end of the log.
+        offset: u32,
+    },
+    /// A record's length field is implausible (e.g. a key longer than [`MAX_KEY_LEN`], or a
+    /// value longer than the caller's output buffer).
+    InvalidSize {
+        /// Offset of the offending record.
+        offset: u32,
+        /// The size that was rejected.
+        size: u32,
+    },
+    /// The separator byte between a record's key and value length was missing or wrong.
+    MissingSeparator {
+        /// Offset of the record missing its separator.
+        offset: u32,
+    },
+    /// A mutating operation was attempted while another was already in progress.
+    AlreadyLocked,
+    /// An error from the underlying flash.
+    Flash(E),
+}
+
+fn padded_len(raw: usize, write_size: usize) -> usize {
+    raw.div_ceil(write_size) * write_size
+}
+
+struct RecordHeader {
+    value_len: u32,
+    value_offset: u32,
+    next_offset: u32,
+}
+
+/// A persistent, string-keyed byte-value store backed by a region of NOR flash.
+pub struct FlashKv<F: NorFlash> {
+    flash: F,
+    base: u32,
+    len: u32,
+    cursor: u32,
+    scanned: bool,
+    locked: bool,
+}
+
+impl<F: NorFlash> FlashKv<F> {
+    /// Create a store over the flash region `[base, base + len)`.
+    ///
+    /// The region is scanned lazily, on first use, to find the end of the existing log.
+    pub fn new(flash: F, base: u32, len: u32) -> Self {
+        Self {
+            flash,
+            base,
+            len,
+            cursor: base,
+            scanned: false,
+            locked: false,
+        }
+    }
+
+    async fn ensure_scanned(&mut self) -> Result<(), Error<F::Error>> {
+        if self.scanned {
+            return Ok(());
+        }
+        let mut key_buf = [0u8; MAX_KEY_LEN];
+        let mut offset = self.base;
+        while offset < self.base + self.len {
+            match self.read_header(offset, &mut key_buf).await? {
+                Some((hdr, _)) => offset = hdr.next_offset,
+                None => break,
+            }
+        }
+        self.cursor = offset;
+        self.scanned = true;
+        Ok(())
+    }
+
+    /// Read the record at `offset`, writing its key into `key_buf`.
+    ///
+    /// Returns `None` once the scan reaches unwritten (erased) flash, signalled by a
+    /// `key_len` of `u32::MAX`.
+    async fn read_header(
+        &mut self,
+        offset: u32,
+        key_buf: &mut [u8],
+    ) -> Result<Option<(RecordHeader, usize)>, Error<F::Error>> {
+        let mut len_buf = [0u8; 4];
+        self.flash.read(offset, &mut len_buf).await.map_err(Error::Flash)?;
+        let key_len = u32::from_le_bytes(len_buf);
+        if key_len == u32::MAX {
+            return Ok(None);
+        }
+        let key_len_usize = key_len as usize;
+        if key_len_usize > key_buf.len() {
+            return Err(Error::InvalidSize { offset, size: key_len });
+        }
+        if (offset as u64) + 4 + key_len as u64 + 1 + 4 > (self.base + self.len) as u64 {
+            return Err(Error::Truncated { offset });
+        }
+        self.flash
+            .read(offset + 4, &mut key_buf[..key_len_usize])
+            .await
+            .map_err(Error::Flash)?;
+        let mut sep = [0u8; 1];
+        self.flash.read(offset + 4 + key_len, &mut sep).await.map_err(Error::Flash)?;
+        if sep[0] != SEPARATOR {
+            return Err(Error::MissingSeparator { offset });
+        }
+        let mut vlen_buf = [0u8; 4];
+        self.flash
+            .read(offset + 4 + key_len + 1, &mut vlen_buf)
+            .await
+            .map_err(Error::Flash)?;
+        let value_len = u32::from_le_bytes(vlen_buf);
+        let value_offset = offset + 4 + key_len + 1 + 4;
+        if (value_offset as u64) + value_len as u64 > (self.base + self.len) as u64 {
+            return Err(Error::Truncated { offset });
+        }
+        let raw = 4 + key_len_usize + 1 + 4 + value_len as usize;
+        let next_offset = offset + padded_len(raw, F::WRITE_SIZE) as u32;
+        Ok(Some((
+            RecordHeader {
+                value_len,
+                value_offset,
+                next_offset,
+            },
+            key_len_usize,
+        )))
+    }
+
+    /// Look up `key`, copying its value into `value` and returning the number of bytes
+    /// written, or `None` if the key is absent or was deleted.
+    pub async fn get(&mut self, key: &str, value: &mut [u8]) -> Result<Option<usize>, Error<F::Error>> {
+        self.ensure_scanned().await?;
+        let mut key_buf = [0u8; MAX_KEY_LEN];
+        let mut offset = self.base;
+        let mut found = None;
+        while offset < self.cursor {
+            let (hdr, key_len) = match self.read_header(offset, &mut key_buf).await? {
+                Some(h) => h,
+                None => break,
+            };
+            if key_buf[..key_len] == *key.as_bytes() {
+                found = if hdr.value_len == 0 {
+                    None
+                } else {
+                    Some((offset, hdr.value_offset, hdr.value_len))
+                };
+            }
+            offset = hdr.next_offset;
+        }
+        match found {
+            None => Ok(None),
+            Some((record_offset, value_offset, value_len)) => {
+                if value.len() < value_len as usize {
+                    return Err(Error::InvalidSize {
+                        offset: record_offset,
+                        size: value_len,
+                    });
+                }
+                self.flash
+                    .read(value_offset, &mut value[..value_len as usize])
+                    .await
+                    .map_err(Error::Flash)?;
+                Ok(Some(value_len as usize))
+            }
+        }
+    }
+
+    /// Set `key` to `value`, appending a new record (compacting first if necessary).
+    ///
+    /// `scratch` is used to stage the record to write, and, if compaction is triggered, to
+    /// stage every surviving record in the region; it must be at least as large as the
+    /// largest single padded record, and large enough to hold all live data for compaction
+    /// to succeed.
+    pub async fn set(&mut self, key: &str, value: &[u8], scratch: &mut [u8]) -> Result<(), Error<F::Error>> {
+        self.write_record(key.as_bytes(), value, scratch).await
+    }
+
+    /// Delete `key` by appending a tombstone record.
+    ///
+    /// See [`set`](Self::set) for the role of `scratch`.
+    pub async fn remove(&mut self, key: &str, scratch: &mut [u8]) -> Result<(), Error<F::Error>> {
+        self.write_record(key.as_bytes(), &[], scratch).await
+    }
+
+    async fn write_record(&mut self, key: &[u8], value: &[u8], scratch: &mut [u8]) -> Result<(), Error<F::Error>> {
+        // A key over MAX_KEY_LEN would be written successfully here but could never be read
+        // back: `read_header` rejects it with `InvalidSize`, and since the log can't skip a
+        // record it can't parse, that error would then surface from every future scan
+        // (`get`, `keys`, even `compact`), permanently bricking the store.
+        if key.len() > MAX_KEY_LEN {
+            return Err(Error::InvalidSize {
+                offset: self.cursor,
+                size: key.len() as u32,
+            });
+        }
+        self.ensure_scanned().await?;
+        if self.locked {
+            return Err(Error::AlreadyLocked);
+        }
+        self.locked = true;
+        let guard = LockGuard { kv: self };
+        guard.kv.write_record_locked(key, value, scratch).await
+    }
+
+    async fn write_record_locked(&mut self, key: &[u8], value: &[u8], scratch: &mut [u8]) -> Result<(), Error<F::Error>> {
+        let raw = 4 + key.len() + 1 + 4 + value.len();
+        let padded = padded_len(raw, F::WRITE_SIZE);
+        if padded > scratch.len() {
+            return Err(Error::SpaceExhausted);
+        }
+        if self.cursor + padded as u32 > self.base + self.len {
+            self.compact(scratch).await?;
+            if self.cursor + padded as u32 > self.base + self.len {
+                return Err(Error::SpaceExhausted);
+            }
+        }
+        encode_record(&mut scratch[..padded], key, value, raw);
+        self.flash.write(self.cursor, &scratch[..padded]).await.map_err(Error::Flash)?;
+        self.cursor += padded as u32;
+        Ok(())
+    }
+
+    /// Replay the live key/value pairs into `scratch`, erase the region, and rewrite only
+    /// the survivors to the front.
+    async fn compact(&mut self, scratch: &mut [u8]) -> Result<(), Error<F::Error>> {
+        let mut scratch_len = 0usize;
+        let mut key_buf = [0u8; MAX_KEY_LEN];
+        let mut offset = self.base;
+        while offset < self.cursor {
+            let (hdr, key_len) = match self.read_header(offset, &mut key_buf).await? {
+                Some(h) => h,
+                None => break,
+            };
+            // Drop any previously-staged record for this key: either this (newer) record
+            // shadows it, or (if this is a tombstone) both should be gone.
+            let mut scan = 0;
+            while scan < scratch_len {
+                let rec =
+                    decode_record(&scratch[scan..scratch_len], F::WRITE_SIZE).expect("scratch holds only our own records");
+                if scratch[scan + 4..scan + 4 + rec.key_len] == key_buf[..key_len] {
+                    scratch.copy_within(scan + rec.padded_len..scratch_len, scan);
+                    scratch_len -= rec.padded_len;
+                    break;
+                }
+                scan += rec.padded_len;
+            }
+
+            if hdr.value_len > 0 {
+                let raw = 4 + key_len + 1 + 4 + hdr.value_len as usize;
+                let padded = padded_len(raw, F::WRITE_SIZE);
+                if scratch_len + padded > scratch.len() {
+                    return Err(Error::SpaceExhausted);
+                }
+                stage_record(
+                    &mut self.flash,
+                    &mut scratch[scratch_len..scratch_len + padded],
+                    &key_buf[..key_len],
+                    hdr.value_offset,
+                    hdr.value_len,
+                    raw,
+                )
+                .await?;
+                scratch_len += padded;
+            }
+
+            offset = hdr.next_offset;
+        }
+
+        self.flash
+            .erase(self.base, self.base + self.len)
+            .await
+            .map_err(Error::Flash)?;
+        self.flash
+            .write(self.base, &scratch[..scratch_len])
+            .await
+            .map_err(Error::Flash)?;
+        self.cursor = self.base + scratch_len as u32;
+        Ok(())
+    }
+
+    /// Iterate over the live keys in the store.
+    pub async fn keys(&mut self) -> Result<KeysIter<'_, F>, Error<F::Error>> {
+        self.ensure_scanned().await?;
+        let base = self.base;
+        Ok(KeysIter { kv: self, offset: base })
+    }
+
+    async fn is_shadowed(&mut self, key: &[u8], from: u32) -> Result<bool, Error<F::Error>> {
+        let mut key_buf = [0u8; MAX_KEY_LEN];
+        let mut offset = from;
+        while offset < self.cursor {
+            let (hdr, key_len) = match self.read_header(offset, &mut key_buf).await? {
+                Some(h) => h,
+                None => break,
+            };
+            if key_buf[..key_len] == *key {
+                return Ok(true);
+            }
+            offset = hdr.next_offset;
+        }
+        Ok(false)
+    }
+}
+
+/// Resets [`FlashKv::locked`] on drop, so a mutation that's cancelled mid-flight (a dropped
+/// `select!` branch, a timeout, executor shutdown) doesn't leave the store permanently
+/// returning [`Error::AlreadyLocked`].
+struct LockGuard<'a, F: NorFlash> {
+    kv: &'a mut FlashKv<F>,
+}
+
+impl<'a, F: NorFlash> Drop for LockGuard<'a, F> {
+    fn drop(&mut self) {
+        self.kv.locked = false;
+    }
+}
+
+/// Cursor returned by [`FlashKv::keys`].
+pub struct KeysIter<'a, F: NorFlash> {
+    kv: &'a mut FlashKv<F>,
+    offset: u32,
+}
+
+impl<'a, F: NorFlash> KeysIter<'a, F> {
+    /// Advance to the next live key, writing it into `buf` and returning its length, or
+    /// `None` once the log is exhausted.
+    pub async fn next(&mut self, buf: &mut [u8]) -> Result<Option<usize>, Error<F::Error>> {
+        let mut key_buf = [0u8; MAX_KEY_LEN];
+        while self.offset < self.kv.cursor {
+            let this_offset = self.offset;
+            let (hdr, key_len) = match self.kv.read_header(this_offset, &mut key_buf).await? {
+                Some(h) => h,
+                None => break,
+            };
+            let next_offset = hdr.next_offset;
+            self.offset = next_offset;
+            if hdr.value_len == 0 {
+                continue;
+            }
+            if self.kv.is_shadowed(&key_buf[..key_len], next_offset).await? {
+                continue;
+            }
+            if buf.len() < key_len {
+                return Err(Error::InvalidSize {
+                    offset: this_offset,
+                    size: key_len as u32,
+                });
+            }
+            buf[..key_len].copy_from_slice(&key_buf[..key_len]);
+            return Ok(Some(key_len));
+        }
+        Ok(None)
+    }
+}
+
+fn encode_record(dst: &mut [u8], key: &[u8], value: &[u8], raw: usize) {
+    dst[..4].copy_from_slice(&(key.len() as u32).to_le_bytes());
+    dst[4..4 + key.len()].copy_from_slice(key);
+    dst[4 + key.len()] = SEPARATOR;
+    let vlen_pos = 4 + key.len() + 1;
+    dst[vlen_pos..vlen_pos + 4].copy_from_slice(&(value.len() as u32).to_le_bytes());
+    dst[vlen_pos + 4..vlen_pos + 4 + value.len()].copy_from_slice(value);
+    for b in &mut dst[raw..] {
+        *b = 0;
+    }
+}
+
+/// Like [`encode_record`], but the value bytes are copied from flash rather than RAM, for
+/// use while staging surviving records during [`FlashKv::compact`].
+async fn stage_record<F: NorFlash>(
+    flash: &mut F,
+    dst: &mut [u8],
+    key: &[u8],
+    value_offset: u32,
+    value_len: u32,
+    raw: usize,
+) -> Result<(), Error<F::Error>> {
+    dst[..4].copy_from_slice(&(key.len() as u32).to_le_bytes());
+    dst[4..4 + key.len()].copy_from_slice(key);
+    dst[4 + key.len()] = SEPARATOR;
+    let vlen_pos = 4 + key.len() + 1;
+    dst[vlen_pos..vlen_pos + 4].copy_from_slice(&value_len.to_le_bytes());
+    let value_start = vlen_pos + 4;
+    flash
+        .read(value_offset, &mut dst[value_start..value_start + value_len as usize])
+        .await
+        .map_err(Error::Flash)?;
+    for b in &mut dst[raw..] {
+        *b = 0;
+    }
+    Ok(())
+}
+
+struct DecodedRecord {
+    key_len: usize,
+    padded_len: usize,
+}
+
+fn decode_record(buf: &[u8], write_size: usize) -> Option<DecodedRecord> {
+    if buf.len() < 9 {
+        return None;
+    }
+    let key_len = u32::from_le_bytes(buf[0..4].try_into().unwrap()) as usize;
+    if buf.len() < 4 + key_len + 1 + 4 || buf[4 + key_len] != SEPARATOR {
+        return None;
+    }
+    let value_len = u32::from_le_bytes(buf[4 + key_len + 1..4 + key_len + 1 + 4].try_into().unwrap()) as usize;
+    let raw = 4 + key_len + 1 + 4 + value_len;
+    Some(DecodedRecord {
+        key_len,
+        padded_len: padded_len(raw, write_size),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+
+    struct FakeFlash {
+        data: Vec<u8>,
+    }
+
+    impl FakeFlash {
+        fn new(size: usize) -> Self {
+            Self {
+                data: std::vec![0xFFu8; size],
+            }
+        }
+    }
+
+    impl embedded_storage::nor_flash::ErrorType for FakeFlash {
+        type Error = core::convert::Infallible;
+    }
+
+    impl embedded_storage_async::nor_flash::ReadNorFlash for FakeFlash {
+        const READ_SIZE: usize = 1;
+
+        async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            let offset = offset as usize;
+            bytes.copy_from_slice(&self.data[offset..offset + bytes.len()]);
+            Ok(())
+        }
+
+        fn capacity(&self) -> usize {
+            self.data.len()
+        }
+    }
+
+    impl embedded_storage_async::nor_flash::NorFlash for FakeFlash {
+        const WRITE_SIZE: usize = 4;
+        const ERASE_SIZE: usize = 64;
+
+        async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+            let offset = offset as usize;
+            self.data[offset..offset + bytes.len()].copy_from_slice(bytes);
+            Ok(())
+        }
+
+        async fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+            for b in &mut self.data[from as usize..to as usize] {
+                *b = 0xFF;
+            }
+            Ok(())
+        }
+    }
+
+    fn kv() -> FlashKv<FakeFlash> {
+        FlashKv::new(FakeFlash::new(64), 0, 64)
+    }
+
+    #[futures_test::test]
+    async fn get_missing_key_returns_none() {
+        let mut kv = kv();
+        let mut buf = [0u8; 16];
+        assert_eq!(None, kv.get("missing", &mut buf).await.unwrap());
+    }
+
+    #[futures_test::test]
+    async fn set_then_get_roundtrips() {
+        let mut kv = kv();
+        let mut scratch = [0u8; 64];
+        kv.set("a", b"hello", &mut scratch).await.unwrap();
+
+        let mut buf = [0u8; 16];
+        let n = kv.get("a", &mut buf).await.unwrap().unwrap();
+        assert_eq!(b"hello", &buf[..n]);
+    }
+
+    #[futures_test::test]
+    async fn overwrite_shadows_earlier_value() {
+        let mut kv = kv();
+        let mut scratch = [0u8; 64];
+        kv.set("a", b"first", &mut scratch).await.unwrap();
+        kv.set("a", b"second", &mut scratch).await.unwrap();
+
+        let mut buf = [0u8; 16];
+        let n = kv.get("a", &mut buf).await.unwrap().unwrap();
+        assert_eq!(b"second", &buf[..n]);
+    }
+
+    #[futures_test::test]
+    async fn remove_deletes_key() {
+        let mut kv = kv();
+        let mut scratch = [0u8; 64];
+        kv.set("a", b"first", &mut scratch).await.unwrap();
+        kv.remove("a", &mut scratch).await.unwrap();
+
+        let mut buf = [0u8; 16];
+        assert_eq!(None, kv.get("a", &mut buf).await.unwrap());
+    }
+
+    #[futures_test::test]
+    async fn compact_reclaims_shadowed_space() {
+        // Each record for "a" is 12 bytes once padded to WRITE_SIZE; the 64-byte region
+        // can't hold 20 of them, so this only succeeds if compaction reclaims the space
+        // shadowed by earlier overwrites.
+        let mut kv = kv();
+        let mut scratch = [0u8; 64];
+        for i in 0..20u8 {
+            kv.set("a", &[i], &mut scratch).await.unwrap();
+        }
+
+        let mut buf = [0u8; 4];
+        let n = kv.get("a", &mut buf).await.unwrap().unwrap();
+        assert_eq!(&[19], &buf[..n]);
+    }
+
+    #[futures_test::test]
+    async fn keys_yields_each_live_key_once() {
+        let mut kv = kv();
+        let mut scratch = [0u8; 64];
+        kv.set("a", b"1", &mut scratch).await.unwrap();
+        kv.set("b", b"2", &mut scratch).await.unwrap();
+        kv.set("a", b"3", &mut scratch).await.unwrap();
+        kv.remove("b", &mut scratch).await.unwrap();
+
+        let mut iter = kv.keys().await.unwrap();
+        let mut seen = Vec::new();
+        let mut buf = [0u8; 16];
+        while let Some(n) = iter.next(&mut buf).await.unwrap() {
+            seen.push(String::from_utf8(buf[..n].to_vec()).unwrap());
+        }
+        seen.sort();
+        assert_eq!(seen, std::vec!["a".to_string()]);
+    }
+
+    #[futures_test::test]
+    async fn truncated_length_field_is_reported() {
+        // key_len must stay <= MAX_KEY_LEN or read_header rejects it with InvalidSize before
+        // ever reaching the truncation check; 58 is small enough to pass that check but still
+        // runs the record past the end of the 64-byte region.
+        let mut flash = FakeFlash::new(64);
+        flash.data[0..4].copy_from_slice(&58u32.to_le_bytes());
+        let mut kv = FlashKv::new(flash, 0, 64);
+
+        let mut buf = [0u8; 16];
+        let err = kv.get("x", &mut buf).await.unwrap_err();
+        assert!(matches!(err, Error::Truncated { offset: 0 }));
+    }
+
+    #[futures_test::test]
+    async fn missing_separator_is_reported() {
+        let mut flash = FakeFlash::new(64);
+        flash.data[0..4].copy_from_slice(&1u32.to_le_bytes());
+        flash.data[4] = b'a';
+        flash.data[5] = 0x00;
+        let mut kv = FlashKv::new(flash, 0, 64);
+
+        let mut buf = [0u8; 16];
+        let err = kv.get("x", &mut buf).await.unwrap_err();
+        assert!(matches!(err, Error::MissingSeparator { offset: 0 }));
+    }
+
+    #[futures_test::test]
+    async fn oversized_key_len_is_reported() {
+        let mut flash = FakeFlash::new(64);
+        flash.data[0..4].copy_from_slice(&(MAX_KEY_LEN as u32 + 1).to_le_bytes());
+        let mut kv = FlashKv::new(flash, 0, 64);
+
+        let mut buf = [0u8; 16];
+        let err = kv.get("x", &mut buf).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidSize { offset: 0, .. }));
+    }
+
+    #[futures_test::test]
+    async fn set_rejects_oversized_key() {
+        let mut kv = kv();
+        let mut scratch = [0u8; 256];
+        let long_key = "x".repeat(MAX_KEY_LEN + 1);
+
+        let err = kv.set(&long_key, b"v", &mut scratch).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidSize { .. }));
+    }
+
+    #[test]
+    fn cancelling_a_mutation_mid_flight_releases_the_lock() {
+        use core::future::Future;
+        use core::task::Poll;
+
+        // Wrap in YieldingAsync so the write actually suspends at least once instead of
+        // resolving on the first poll, giving us a real mid-flight point to cancel at.
+        let mut kv = FlashKv::new(crate::adapter::YieldingAsync::new(FakeFlash::new(64)), 0, 64);
+        let mut scratch = [0u8; 64];
+        let mut cx = futures_test::task::noop_context();
+
+        // Finish the initial log scan first; otherwise the first poll below would suspend
+        // inside that scan, before `set` has taken the lock.
+        {
+            let mut scan = Box::pin(kv.ensure_scanned());
+            while scan.as_mut().poll(&mut cx).is_pending() {}
+        }
+
+        {
+            let mut fut = Box::pin(kv.set("a", b"first", &mut scratch));
+            // Poll once so the mutation is suspended behind a yield point, then drop the
+            // future without ever completing it, as a cancelled `select!` branch, a timeout,
+            // or executor shutdown would.
+            assert!(matches!(fut.as_mut().poll(&mut cx), Poll::Pending));
+        }
+
+        let mut scratch = [0u8; 64];
+        let mut fut = Box::pin(kv.set("a", b"second", &mut scratch));
+        loop {
+            match fut.as_mut().poll(&mut cx) {
+                Poll::Ready(result) => {
+                    result.unwrap();
+                    break;
+                }
+                Poll::Pending => continue,
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+#![cfg_attr(not(test), no_std)]
+
+//! Collection of utilities to use together with embassy and embedded-hal.
+
+pub mod adapter;
+pub mod kv;